@@ -23,14 +23,52 @@ use eyre::EyreContext;
 use indenter::indented;
 use std::error::Error;
 
+#[cfg(feature = "backtrace")]
+use backtrace::Backtrace;
+
+#[cfg(feature = "capture-spantrace")]
+use tracing_error::{SpanTrace, SpanTraceStatus};
+
+mod section;
+pub use section::{Help, Section};
+
+use section::{SectionExt, SECTION_KINDS};
+
 /// A custom context type for minimal error reporting via `eyre`
-#[derive(Debug)]
-pub struct Context;
+pub struct Context {
+    #[cfg(feature = "backtrace")]
+    backtrace: Option<Backtrace>,
+    #[cfg(feature = "capture-spantrace")]
+    span_trace: SpanTrace,
+    sections: Vec<SectionExt>,
+}
+
+impl core::fmt::Debug for Context {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Context").finish()
+    }
+}
 
 impl EyreContext for Context {
     #[allow(unused_variables)]
     fn default(error: &(dyn Error + 'static)) -> Self {
-        Self
+        #[cfg(feature = "backtrace")]
+        let backtrace = if backtrace_enabled() {
+            Some(Backtrace::new())
+        } else {
+            None
+        };
+
+        #[cfg(feature = "capture-spantrace")]
+        let span_trace = SpanTrace::capture();
+
+        Self {
+            #[cfg(feature = "backtrace")]
+            backtrace,
+            #[cfg(feature = "capture-spantrace")]
+            span_trace,
+            sections: Vec::new(),
+        }
     }
 
     fn debug(
@@ -38,24 +76,45 @@ impl EyreContext for Context {
         error: &(dyn Error + 'static),
         f: &mut core::fmt::Formatter<'_>,
     ) -> core::fmt::Result {
-        use core::fmt::Write as _;
-
         if f.alternate() {
             return core::fmt::Debug::fmt(error, f);
         }
 
         write!(f, "{}", error)?;
 
-        if let Some(cause) = error.source() {
-            write!(f, "\n\nCaused by:")?;
-            let multiple = cause.source().is_some();
-            for (n, error) in Chain::new(cause).enumerate() {
-                writeln!(f)?;
-                if multiple {
-                    write!(indented(f).ind(n), "{}", error)?;
-                } else {
-                    write!(indented(f), "{}", error)?;
-                }
+        write_causes(error, f)?;
+
+        for kind in SECTION_KINDS {
+            for section in self.sections.iter().filter(|section| section.kind == kind) {
+                write!(f, "\n\n{}: {}", section.kind.header(), section.content)?;
+            }
+        }
+
+        #[cfg(feature = "capture-spantrace")]
+        if self.span_trace.status() == SpanTraceStatus::CAPTURED {
+            write!(f, "\n\nSpan trace:\n{}", self.span_trace)?;
+        }
+
+        #[cfg(feature = "backtrace")]
+        if let Some(backtrace) = &self.backtrace {
+            write!(f, "\n\nStack backtrace:\n{:?}", backtrace)?;
+        }
+
+        Ok(())
+    }
+
+    fn display(
+        &self,
+        error: &(dyn Error + 'static),
+        f: &mut core::fmt::Formatter<'_>,
+    ) -> core::fmt::Result {
+        write!(f, "{}", error)?;
+
+        if f.alternate() {
+            write_causes(error, f)?;
+        } else {
+            for cause in Chain::new(error).skip(1) {
+                write!(f, ": {}", cause)?;
             }
         }
 
@@ -63,12 +122,47 @@ impl EyreContext for Context {
     }
 }
 
-/// A type alias for `eyre::Report<stable_eyre::Context>`
+/// Write the "Caused by:" block shared by the default `debug` output and the
+/// alternate (`{:#}`) `display` output.
+fn write_causes(error: &(dyn Error + 'static), f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    use core::fmt::Write as _;
+
+    if let Some(cause) = error.source() {
+        write!(f, "\n\nCaused by:")?;
+        let multiple = cause.source().is_some();
+        for (n, error) in Chain::new(cause).enumerate() {
+            writeln!(f)?;
+            if multiple {
+                write!(indented(f).ind(n), "{}", error)?;
+            } else {
+                write!(indented(f), "{}", error)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Check the `RUST_LIB_BACKTRACE` and `RUST_BACKTRACE` environment variables
+/// to decide whether a backtrace should be captured, giving `RUST_LIB_BACKTRACE`
+/// priority as `std::backtrace::Backtrace` does.
+#[cfg(feature = "backtrace")]
+fn backtrace_enabled() -> bool {
+    match std::env::var("RUST_LIB_BACKTRACE") {
+        Ok(s) => s != "0",
+        Err(_) => match std::env::var("RUST_BACKTRACE") {
+            Ok(s) => s != "0",
+            Err(_) => false,
+        },
+    }
+}
+
+/// A type alias for `eyre::Report<simple_eyre::Context>`
 ///
 /// # Example
 ///
 /// ```rust
-/// use stable_eyre::Report;
+/// use simple_eyre::Report;
 ///
 /// # struct Config;
 /// fn try_thing(path: &str) -> Result<Config, Report> {
@@ -78,12 +172,12 @@ impl EyreContext for Context {
 /// ```
 pub type Report = eyre::Report<Context>;
 
-/// A type alias for `Result<T, stable_eyre::Report>`
+/// A type alias for `Result<T, simple_eyre::Report>`
 ///
 /// # Example
 ///
 ///```
-/// fn main() -> stable_eyre::Result<()> {
+/// fn main() -> simple_eyre::Result<()> {
 ///
 ///     // ...
 ///
@@ -91,3 +185,89 @@ pub type Report = eyre::Report<Context>;
 /// }
 /// ```
 pub type Result<T, E = Report> = core::result::Result<T, E>;
+
+/// Install a panic hook that formats panics through the same minimal
+/// reporter used for [`Report`]'s error output.
+///
+/// # Details
+///
+/// `simple_eyre::Report` already uses [`Context`] for its error reports by
+/// construction, so the only thing left to wire up globally is panic
+/// formatting. Call this once, early in `main`, before any panics could
+/// occur, to get consistent output for both returned errors and panics.
+pub fn install() {
+    install_panic_hook();
+}
+
+fn install_panic_hook() {
+    std::panic::set_hook(Box::new(move |panic_info| {
+        eprintln!("{}", panic_report(panic_info));
+    }));
+}
+
+/// Format a panic for the panic hook: a header line, the panic message, its
+/// location, and, with the `backtrace` feature and when `RUST_LIB_BACKTRACE`
+/// or `RUST_BACKTRACE` enable capture, a freshly captured backtrace under the
+/// same "Stack backtrace:" header that [`Context`] uses.
+fn panic_report(panic_info: &std::panic::PanicHookInfo<'_>) -> String {
+    use std::fmt::Write as _;
+
+    let mut report = String::new();
+
+    let payload = panic_info
+        .payload()
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| panic_info.payload().downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("<non string panic payload>");
+    let _ = write!(report, "The application panicked (crashed).\nMessage:  {}", payload);
+
+    if let Some(location) = panic_info.location() {
+        let _ = write!(report, "\nLocation: {}", location);
+    }
+
+    #[cfg(feature = "backtrace")]
+    if backtrace_enabled() {
+        let _ = write!(report, "\n\nStack backtrace:\n{:?}", Backtrace::new());
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sections_print_grouped_by_kind_regardless_of_attach_order() {
+        let report: Report = eyre::eyre!("boom");
+        let report = report
+            .suggestion("try turning it off and on again")
+            .note("first note")
+            .warning("storage almost full");
+
+        let rendered = format!("{:?}", report);
+        let note_pos = rendered.find("Note: first note").unwrap();
+        let warning_pos = rendered.find("Warning: storage almost full").unwrap();
+        let suggestion_pos = rendered
+            .find("Suggestion: try turning it off and on again")
+            .unwrap();
+
+        assert!(note_pos < warning_pos);
+        assert!(warning_pos < suggestion_pos);
+    }
+
+    #[test]
+    fn display_alternate_mirrors_debug_cause_chain() {
+        let report: Report = eyre::eyre!("root cause");
+        let report = report.wrap_err("middle").wrap_err("outer");
+
+        assert_eq!(format!("{}", report), "outer: middle: root cause");
+
+        let alternate = format!("{:#}", report);
+        assert!(alternate.starts_with("outer"));
+        assert!(alternate.contains("Caused by:"));
+        assert!(alternate.contains("middle"));
+        assert!(alternate.contains("root cause"));
+    }
+}
@@ -0,0 +1,219 @@
+//! Extension traits for attaching notes, warnings, and suggestions to a
+//! [`Report`] without inserting them into the chain of sources.
+use std::fmt::Display;
+
+use crate::{Report, Result};
+
+/// An extension trait for attaching help text to errors, to be printed
+/// alongside the error after the chain of causes.
+///
+/// This trait is sealed and cannot be implemented for types outside of
+/// `simple-eyre`.
+pub trait Section: private::Sealed {
+    /// The type produced by the methods on this trait.
+    type Return;
+
+    /// Add a note to an error, to be printed after the chain of causes.
+    fn note<D>(self, note: D) -> Self::Return
+    where
+        D: Display + Send + Sync + 'static;
+
+    /// Add a note to an error, to be printed after the chain of causes, which
+    /// is lazily evaluated only once an error does occur.
+    fn with_note<D, F>(self, note: F) -> Self::Return
+    where
+        D: Display + Send + Sync + 'static,
+        F: FnOnce() -> D;
+
+    /// Add a warning to an error, to be printed after the chain of causes and
+    /// any notes.
+    fn warning<D>(self, warning: D) -> Self::Return
+    where
+        D: Display + Send + Sync + 'static;
+
+    /// Add a warning to an error, lazily evaluated only once an error does
+    /// occur.
+    fn with_warning<D, F>(self, warning: F) -> Self::Return
+    where
+        D: Display + Send + Sync + 'static,
+        F: FnOnce() -> D;
+
+    /// Add a suggestion to an error, to be printed after the chain of causes,
+    /// notes, and warnings.
+    fn suggestion<D>(self, suggestion: D) -> Self::Return
+    where
+        D: Display + Send + Sync + 'static;
+
+    /// Add a suggestion to an error, lazily evaluated only once an error does
+    /// occur.
+    fn with_suggestion<D, F>(self, suggestion: F) -> Self::Return
+    where
+        D: Display + Send + Sync + 'static,
+        F: FnOnce() -> D;
+}
+
+/// An alias for [`Section`], kept around for parity with `color-eyre`'s
+/// naming of the same trait.
+pub use Section as Help;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SectionKind {
+    Note,
+    Warning,
+    Suggestion,
+}
+
+/// The order sections of each kind are printed in, regardless of the order
+/// they were attached in.
+pub(crate) const SECTION_KINDS: [SectionKind; 3] =
+    [SectionKind::Note, SectionKind::Warning, SectionKind::Suggestion];
+
+impl SectionKind {
+    pub(crate) fn header(&self) -> &'static str {
+        match self {
+            SectionKind::Note => "Note",
+            SectionKind::Warning => "Warning",
+            SectionKind::Suggestion => "Suggestion",
+        }
+    }
+}
+
+pub(crate) struct SectionExt {
+    pub(crate) kind: SectionKind,
+    pub(crate) content: Box<dyn Display + Send + Sync + 'static>,
+}
+
+trait ReportExt: Sized {
+    fn add_section(self, kind: SectionKind, content: Box<dyn Display + Send + Sync>) -> Self;
+}
+
+impl ReportExt for Report {
+    fn add_section(mut self, kind: SectionKind, content: Box<dyn Display + Send + Sync>) -> Self {
+        self.context_mut().sections.push(SectionExt { kind, content });
+        self
+    }
+}
+
+impl<T, E> Section for std::result::Result<T, E>
+where
+    E: Into<Report>,
+{
+    type Return = Result<T>;
+
+    fn note<D>(self, note: D) -> Self::Return
+    where
+        D: Display + Send + Sync + 'static,
+    {
+        self.map_err(|error| error.into().add_section(SectionKind::Note, Box::new(note)))
+    }
+
+    fn with_note<D, F>(self, note: F) -> Self::Return
+    where
+        D: Display + Send + Sync + 'static,
+        F: FnOnce() -> D,
+    {
+        self.map_err(|error| {
+            error.into().add_section(SectionKind::Note, Box::new(note()))
+        })
+    }
+
+    fn warning<D>(self, warning: D) -> Self::Return
+    where
+        D: Display + Send + Sync + 'static,
+    {
+        self.map_err(|error| {
+            error.into().add_section(SectionKind::Warning, Box::new(warning))
+        })
+    }
+
+    fn with_warning<D, F>(self, warning: F) -> Self::Return
+    where
+        D: Display + Send + Sync + 'static,
+        F: FnOnce() -> D,
+    {
+        self.map_err(|error| {
+            error
+                .into()
+                .add_section(SectionKind::Warning, Box::new(warning()))
+        })
+    }
+
+    fn suggestion<D>(self, suggestion: D) -> Self::Return
+    where
+        D: Display + Send + Sync + 'static,
+    {
+        self.map_err(|error| {
+            error
+                .into()
+                .add_section(SectionKind::Suggestion, Box::new(suggestion))
+        })
+    }
+
+    fn with_suggestion<D, F>(self, suggestion: F) -> Self::Return
+    where
+        D: Display + Send + Sync + 'static,
+        F: FnOnce() -> D,
+    {
+        self.map_err(|error| {
+            error
+                .into()
+                .add_section(SectionKind::Suggestion, Box::new(suggestion()))
+        })
+    }
+}
+
+impl Section for Report {
+    type Return = Report;
+
+    fn note<D>(self, note: D) -> Self::Return
+    where
+        D: Display + Send + Sync + 'static,
+    {
+        self.add_section(SectionKind::Note, Box::new(note))
+    }
+
+    fn with_note<D, F>(self, note: F) -> Self::Return
+    where
+        D: Display + Send + Sync + 'static,
+        F: FnOnce() -> D,
+    {
+        self.add_section(SectionKind::Note, Box::new(note()))
+    }
+
+    fn warning<D>(self, warning: D) -> Self::Return
+    where
+        D: Display + Send + Sync + 'static,
+    {
+        self.add_section(SectionKind::Warning, Box::new(warning))
+    }
+
+    fn with_warning<D, F>(self, warning: F) -> Self::Return
+    where
+        D: Display + Send + Sync + 'static,
+        F: FnOnce() -> D,
+    {
+        self.add_section(SectionKind::Warning, Box::new(warning()))
+    }
+
+    fn suggestion<D>(self, suggestion: D) -> Self::Return
+    where
+        D: Display + Send + Sync + 'static,
+    {
+        self.add_section(SectionKind::Suggestion, Box::new(suggestion))
+    }
+
+    fn with_suggestion<D, F>(self, suggestion: F) -> Self::Return
+    where
+        D: Display + Send + Sync + 'static,
+        F: FnOnce() -> D,
+    {
+        self.add_section(SectionKind::Suggestion, Box::new(suggestion()))
+    }
+}
+
+mod private {
+    pub trait Sealed {}
+
+    impl<T, E> Sealed for std::result::Result<T, E> where E: Into<crate::Report> {}
+    impl Sealed for crate::Report {}
+}